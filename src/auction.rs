@@ -9,14 +9,65 @@ use sp_std::{
 	cmp::{Eq, PartialEq},
 	fmt::Debug,
 	result,
+	vec::Vec,
 };
 
+/// A price floor enforced by an auction, optionally kept secret until reveal.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum PriceFloor<Balance, Hash> {
+	/// No floor is enforced, any bid is accepted.
+	None,
+	/// Bids strictly below `Balance` are rejected.
+	MinimumPrice(Balance),
+	/// A commitment `hash(min_price || salt)` taken at auction creation.
+	/// The actual floor is unknown until revealed via `reveal_price_floor`,
+	/// so it cannot be front-run.
+	BlindedPrice(Hash),
+}
+
+/// How many winning bids an auction settles.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum WinnerLimit {
+	/// Every standing bid wins, there is no cap on the bid book size.
+	Unlimited,
+	/// Only the top `u32` standing bids win; lower bids are evicted from
+	/// the bid book as higher ones arrive. `Capped(0)` admits no bids at
+	/// all and must be rejected by `new_auction`/`update_auction`.
+	Capped(u32),
+}
+
+/// The lifecycle state of an auction at a given block.
+#[cfg_attr(feature = "std", derive(PartialEq, Eq))]
+#[derive(Encode, Decode, RuntimeDebug)]
+pub enum AuctionStatus<BlockNumber> {
+	/// The auction's `start` has not been reached yet.
+	NotStarted,
+	/// The auction is live and accepting bids until `ends_at`.
+	InProgress {
+		/// The block at which the auction is due to end.
+		ends_at: Option<BlockNumber>,
+	},
+	/// The auction's `end` has passed but it has not yet been settled.
+	EndingPeriod,
+	/// The auction has been settled and removed.
+	Closed,
+}
+
 /// Auction info.
 #[cfg_attr(feature = "std", derive(PartialEq, Eq))]
 #[derive(Encode, Decode, RuntimeDebug)]
-pub struct AuctionInfo<AccountId, CurrencyId, Balance, BlockNumber> {
-	/// Current bidder, their currency and bid price.
-	pub bid: Option<(AccountId, CurrencyId, Balance)>,
+pub struct AuctionInfo<AccountId, CurrencyId, Balance, BlockNumber, Hash> {
+	/// The account allowed to update or remove this auction, and to hand
+	/// off control via `set_authority`.
+	pub authority: AccountId,
+	/// The account that receives the winning payment(s) when the auction
+	/// is settled, which may differ from `authority`.
+	pub beneficiary: AccountId,
+	/// How many winners this auction settles. Single-winner auctions use
+	/// `WinnerLimit::Capped(1)`.
+	pub winner_limit: WinnerLimit,
 	/// Currency accepted for the auction
 	pub accepts: Option<CurrencyId>,
 	/// Currency dispensed by the auction
@@ -25,6 +76,21 @@ pub struct AuctionInfo<AccountId, CurrencyId, Balance, BlockNumber> {
 	pub start: BlockNumber,
 	/// Define which block this auction will be ended.
 	pub end: Option<BlockNumber>,
+	/// The reserve price of the auction. A `PriceFloor::MinimumPrice` is
+	/// enforced when a bid is placed; a `PriceFloor::BlindedPrice` is only
+	/// enforced once revealed, so bids below it are accepted until then.
+	pub price_floor: PriceFloor<Balance, Hash>,
+	/// If a bid arrives within `end_gap` blocks of `end`, `end` should be
+	/// pushed out to `now + end_gap` to deter last-block sniping. `None`
+	/// disables the extension. Nothing in this trait computes or enforces
+	/// this automatically; it is advisory data for
+	/// `AuctionHandler::on_new_bid` to read and act on via
+	/// `OnNewBidResult::auction_end_change`.
+	pub end_gap: Option<BlockNumber>,
+	/// A hard ceiling `on_new_bid` should cap the `end_gap` extension at, so
+	/// the auction is guaranteed to close eventually. Like `end_gap`, this
+	/// is advisory: enforcing it is entirely the handler's responsibility.
+	pub max_end: Option<BlockNumber>,
 }
 
 /// Abstraction over a simple auction system.
@@ -35,29 +101,78 @@ pub trait Auction<AccountId, CurrencyId, BlockNumber> {
 	type AuctionId: FullCodec + Default + Copy + Eq + PartialEq + MaybeSerializeDeserialize + Bounded + Debug;
 	/// The price to bid.
 	type Balance: AtLeast32Bit + FullCodec + Copy + MaybeSerializeDeserialize + Debug + Default;
+	/// The hash used to commit a blinded price floor.
+	type Hash: FullCodec + Eq + PartialEq + Copy + MaybeSerializeDeserialize + Debug;
 
 	/// The auction info of `id`
-	fn auction_info(id: Self::AuctionId) -> Option<AuctionInfo<AccountId, Self::CurrencyId, Self::Balance, BlockNumber>>;
-	/// Update the auction info of `id` with `info`
-	fn update_auction(id: Self::AuctionId, info: AuctionInfo<AccountId, Self::CurrencyId, Self::Balance, BlockNumber>) -> DispatchResult;
-	/// Create new auction with specific startblock and endblock, 
-	/// a specific accepted currency and a specific dispensed currency, 
-	/// return the id of the auction.
+	fn auction_info(id: Self::AuctionId) -> Option<AuctionInfo<AccountId, Self::CurrencyId, Self::Balance, BlockNumber, Self::Hash>>;
+	/// Update the auction info of `id` with `info`, overwriting whatever is
+	/// currently stored (including any anti-snipe `end` extension already
+	/// computed for the bid in `AuctionHandler::on_new_bid`). Fails unless
+	/// `current` is the auction's existing authority.
+	fn update_auction(
+		id: Self::AuctionId,
+		current: &AccountId,
+		info: AuctionInfo<AccountId, Self::CurrencyId, Self::Balance, BlockNumber, Self::Hash>,
+	) -> DispatchResult;
+	/// Create a new auction from `info`, returning its id. Taking the whole
+	/// `AuctionInfo` rather than its fields as loose positional parameters
+	/// avoids transposing lookalike fields such as `authority`/`beneficiary`
+	/// or the three `Option<BlockNumber>` fields. Must fail with
+	/// `info.winner_limit == WinnerLimit::Capped(0)`, which admits no bids.
 	fn new_auction(
-		start: BlockNumber, 
-		end: Option<BlockNumber>, 
-		accepts: Option<CurrencyId>, 
-		dispenses: Option<CurrencyId>,
+		info: AuctionInfo<AccountId, Self::CurrencyId, Self::Balance, BlockNumber, Self::Hash>,
 	) -> result::Result<Self::AuctionId, DispatchError>;
-	/// Remove auction by `id`
-	fn remove_auction(id: Self::AuctionId);
+	/// Remove auction by `id`. Fails unless `current` is the auction's
+	/// existing authority.
+	fn remove_auction(id: Self::AuctionId, current: &AccountId) -> DispatchResult;
+	/// Hand off `authority` over auction `id` from `current` to `new`, e.g.
+	/// from the pallet that launched the auction to governance. Fails if
+	/// `current` is not the auction's current authority.
+	fn set_authority(id: Self::AuctionId, current: &AccountId, new: AccountId) -> DispatchResult;
+	/// Reveal the `min_price` and `salt` behind a `PriceFloor::BlindedPrice`
+	/// commitment of auction `id`. Must be checked against the stored hash
+	/// before `on_auction_ended` is allowed to settle the auction.
+	fn reveal_price_floor(id: Self::AuctionId, min_price: Self::Balance, salt: Self::Hash) -> DispatchResult;
+	/// The current standing bids of auction `id`, sorted by price in
+	/// descending order. Bounded by `winner_limit`: once the book is full,
+	/// inserting a higher bid evicts the lowest standing one, which
+	/// `AuctionHandler::on_new_bid` is told about via its `evicted_bid`
+	/// parameter so it can be credited to `claimable` immediately. By the
+	/// time `on_auction_ended` fires, every eviction has already been
+	/// refunded this way, so `bids(id)` and `winners` coincide.
+	fn bids(id: Self::AuctionId) -> Vec<(AccountId, Self::CurrencyId, Self::Balance)>;
+	/// The lifecycle state of auction `id` at block `now`. While
+	/// `auction_info(id)` is `Some`, this is derived from its fields
+	/// (`start`/`end`/settlement). Once the auction has been settled and
+	/// removed, `auction_info(id)` is `None` and this must return
+	/// `AuctionStatus::Closed`.
+	fn auction_status(now: BlockNumber, id: Self::AuctionId) -> AuctionStatus<BlockNumber>;
+	/// Whether `who` is among the winners of auction `id`.
+	fn has_won(id: Self::AuctionId, who: &AccountId) -> bool;
+	/// Cancel `who`'s standing bid on auction `id`, moving its reserved
+	/// amount to their claimable balance rather than transferring it back
+	/// directly.
+	fn cancel_bid(who: AccountId, id: Self::AuctionId) -> DispatchResult;
+	/// The amount `who` can currently withdraw from auction `id` via
+	/// `claim_returns`.
+	fn claimable(id: Self::AuctionId, who: &AccountId) -> Self::Balance;
+	/// Withdraw `who`'s claimable balance from auction `id`. Used instead of
+	/// an inline refund so a single bidder's failed transfer cannot stall
+	/// new bids.
+	fn claim_returns(who: AccountId, id: Self::AuctionId) -> DispatchResult;
 }
 
 /// The result of bid handling.
 pub struct OnNewBidResult<BlockNumber> {
 	/// Indicates if the bid was accepted
 	pub accept_bid: bool,
-	/// The auction end change.
+	/// The auction end change. When `end_gap` is set and this bid lands
+	/// within `end_gap` blocks of the current `end`, the handler must set
+	/// this to push `end` out to `now + end_gap`, capped at `max_end`, to
+	/// deter last-block sniping — the `Auction` trait itself neither
+	/// computes nor enforces this, it only stores `end_gap`/`max_end` for
+	/// the handler to read.
 	pub auction_end_change: Change<Option<BlockNumber>>,
 }
 
@@ -65,15 +180,29 @@ pub struct OnNewBidResult<BlockNumber> {
 pub trait AuctionHandler<AccountId, CurrencyId, Balance, BlockNumber, AuctionId> {
 	/// Called when new bid is received.
 	/// The return value determines if the bid should be accepted and update
-	/// auction end time. Implementation should reserve money from current
-	/// winner and refund previous winner.
+	/// auction end time; see `OnNewBidResult::auction_end_change` for the
+	/// `end_gap` anti-snipe contract. Implementation should reserve money
+	/// from the new bidder and credit `evicted_bid`'s reserved amount, if
+	/// any, to its claimable balance (reported by `claimable`, withdrawn via
+	/// `claim_returns`), rather than pushing a refund inline. For a `WinnerLimit::Capped(1)` auction
+	/// `evicted_bid` is the previous top bid; for `Capped(n)` it is whichever
+	/// standing bid the bid book evicts to make room, if the book was full;
+	/// for `Unlimited` it is always `None`. A bid below a known
+	/// `PriceFloor::MinimumPrice` must be rejected here; a blinded floor is
+	/// only enforced at reveal time, so a winner whose bid turns out to be
+	/// below the revealed floor must be discarded in `on_auction_ended`.
 	fn on_new_bid(
 		now: BlockNumber,
 		id: AuctionId,
 		currency_id: CurrencyId,
 		new_bid: (AccountId, CurrencyId, Balance),
-		last_bid: Option<(AccountId, CurrencyId, Balance)>,
+		evicted_bid: Option<(AccountId, CurrencyId, Balance)>,
 	) -> OnNewBidResult<BlockNumber>;
-	/// End an auction with `winner`
-	fn on_auction_ended(id: AuctionId, winner: Option<(AccountId, CurrencyId, Balance)>);
+	/// End an auction with its settled `winners`, and route the winning
+	/// payment(s) to the auction's `beneficiary` rather than assuming the
+	/// caller. Bidders evicted from the bid book were already credited to
+	/// their claimable balance via `on_new_bid`'s `evicted_bid`, so
+	/// `winners` holds only the standing bids; evicted and cancelled bids
+	/// are paid out separately through `claim_returns`.
+	fn on_auction_ended(id: AuctionId, winners: Vec<(AccountId, CurrencyId, Balance)>);
 }